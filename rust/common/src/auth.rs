@@ -0,0 +1,144 @@
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+
+/// Verified claims for the bearer token on the current request, inserted
+/// as a request extension by [`require_bearer`]. Handlers pull this out
+/// with `Extension<Claims>` to enforce per-scope access.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Claims {
+    pub sub: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    exp: usize,
+}
+
+impl Claims {
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
+
+pub struct AuthError {
+    status: StatusCode,
+    message: &'static str,
+}
+
+impl AuthError {
+    fn unauthorized(message: &'static str) -> Self {
+        Self { status: StatusCode::UNAUTHORIZED, message }
+    }
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        let body = Json(serde_json::json!({
+            "error": { "code": self.status.as_u16(), "message": self.message }
+        }));
+        (self.status, body).into_response()
+    }
+}
+
+/// `tower` middleware that validates an `Authorization: Bearer <jwt>`
+/// header against `AUTH_JWT_SECRET` (HS256) and inserts the decoded
+/// [`Claims`] as a request extension. Rejects with a JSON 401 when the
+/// header is missing or the token fails to verify.
+pub async fn require_bearer(req: Request, next: Next) -> Result<Response, AuthError> {
+    let (mut parts, body) = req.into_parts();
+
+    let TypedHeader(Authorization(bearer)) =
+        TypedHeader::<Authorization<Bearer>>::from_request_parts(&mut parts, &())
+            .await
+            .map_err(|_| AuthError::unauthorized("missing bearer token"))?;
+
+    let secret = std::env::var("AUTH_JWT_SECRET")
+        .map_err(|_| AuthError::unauthorized("auth not configured"))?;
+
+    let data = jsonwebtoken::decode::<Claims>(
+        bearer.token(),
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::new(Algorithm::HS256),
+    )
+    .map_err(|_| AuthError::unauthorized("invalid bearer token"))?;
+
+    parts.extensions.insert(data.claims);
+    let req = Request::from_parts(parts, body);
+    Ok(next.run(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request as HttpRequest;
+    use axum::http::StatusCode as HttpStatusCode;
+    use axum::{middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    // All tests in this module configure the same secret so they can run
+    // concurrently without racing each other over the shared process env.
+    const TEST_SECRET: &str = "common-auth-test-secret";
+
+    fn guarded_app() -> Router {
+        std::env::set_var("AUTH_JWT_SECRET", TEST_SECRET);
+        Router::new()
+            .route("/protected", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn(require_bearer))
+    }
+
+    fn token(secret: &str) -> String {
+        let claims =
+            serde_json::json!({ "sub": "tester", "scopes": ["intel:read"], "exp": 9_999_999_999u64 });
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(Algorithm::HS256),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_bearer_token() {
+        let request = HttpRequest::builder().uri("/protected").body(Body::empty()).unwrap();
+
+        let response = guarded_app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), HttpStatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn rejects_token_signed_with_wrong_secret() {
+        let request = HttpRequest::builder()
+            .uri("/protected")
+            .header("authorization", format!("Bearer {}", token("a-different-secret")))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = guarded_app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), HttpStatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn accepts_valid_bearer_token() {
+        let request = HttpRequest::builder()
+            .uri("/protected")
+            .header("authorization", format!("Bearer {}", token(TEST_SECRET)))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = guarded_app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), HttpStatusCode::OK);
+    }
+}
@@ -0,0 +1,144 @@
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+/// Structured error type shared by every handler across intel-svc and
+/// render-svc, serialized as `{ "error": { "code", "message" } }` with
+/// the matching status.
+pub enum AppError {
+    BadRequest(String),
+    Forbidden(String),
+    Internal(String),
+    BadGateway(String),
+}
+
+impl AppError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::BadRequest(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::Internal(message.into())
+    }
+
+    /// An upstream dependency (e.g. object storage) failed or refused
+    /// the request.
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::BadGateway(message.into())
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::BadGateway(_) => StatusCode::BAD_GATEWAY,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::BadRequest(m) | Self::Forbidden(m) | Self::Internal(m) | Self::BadGateway(m) => {
+                m
+            }
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let body = axum::Json(serde_json::json!({
+            "error": { "code": status.as_u16(), "message": self.message() }
+        }));
+        (status, body).into_response()
+    }
+}
+
+/// Drop-in replacement for `axum::Json` whose extraction rejection is a
+/// structured [`AppError`] instead of axum's default plain-text 422.
+pub struct Json<T>(pub T);
+
+#[axum::async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    axum::Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::Json::<T>::from_request(req, state).await {
+            Ok(axum::Json(value)) => Ok(Self(value)),
+            Err(rejection) => Err(AppError::bad_request(rejection.body_text())),
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request as HttpRequest, StatusCode as HttpStatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use serde::Deserialize;
+    use tower::ServiceExt;
+
+    #[derive(Deserialize)]
+    struct Payload {
+        #[allow(dead_code)]
+        name: String,
+    }
+
+    fn app() -> Router {
+        Router::new().route("/echo", post(|Json(payload): Json<Payload>| async move {
+            axum::Json(serde_json::json!({ "name": payload.name }))
+        }))
+    }
+
+    #[tokio::test]
+    async fn malformed_body_yields_structured_error() {
+        let request = HttpRequest::builder()
+            .method("POST")
+            .uri("/echo")
+            .header("content-type", "application/json")
+            .body(Body::from("not json"))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), HttpStatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(parsed["error"]["message"].is_string());
+        assert_eq!(parsed["error"]["code"], 422);
+    }
+
+    #[tokio::test]
+    async fn well_formed_body_passes_through() {
+        let request = HttpRequest::builder()
+            .method("POST")
+            .uri("/echo")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"name":"Ada"}"#))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), HttpStatusCode::OK);
+    }
+}
@@ -0,0 +1,8 @@
+//! Shared subsystems used by intel-svc, render-svc, svc_api, and the
+//! gateway: bearer auth, structured errors, Prometheus metrics, and
+//! graceful shutdown. Kept as a real dependency rather than copy-pasted
+//! modules so a fix to JWT validation or histogram buckets lands once.
+pub mod auth;
+pub mod error;
+pub mod metrics;
+pub mod shutdown;
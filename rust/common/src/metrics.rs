@@ -0,0 +1,51 @@
+use axum::{extract::MatchedPath, extract::Request, middleware::Next, response::IntoResponse};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+const EXPONENTIAL_SECONDS: &[f64] =
+    &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+static RECORDER: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Installs the process-wide Prometheus recorder and returns a handle
+/// whose `render()` backs the `/metrics` route. Installing a second
+/// global recorder would panic, so repeat calls (e.g. `app()` built
+/// more than once in a test process) just hand back the same handle.
+pub fn install_recorder() -> PrometheusHandle {
+    RECORDER
+        .get_or_init(|| {
+            PrometheusBuilder::new()
+                .set_buckets_for_metric(
+                    Matcher::Full("http_requests_duration_seconds".to_string()),
+                    EXPONENTIAL_SECONDS,
+                )
+                .expect("bucket boundaries are non-empty")
+                .install_recorder()
+                .expect("failed to install Prometheus recorder")
+        })
+        .clone()
+}
+
+/// `tower` middleware that records a request counter and latency
+/// histogram per matched route, method, and status code.
+pub async fn track_metrics(req: Request, next: Next) -> impl IntoResponse {
+    let start = Instant::now();
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str().to_owned())
+        .unwrap_or_else(|| req.uri().path().to_owned());
+    let method = req.method().clone();
+
+    let response = next.run(req).await;
+
+    let latency = start.elapsed().as_secs_f64();
+    let status = response.status().as_u16().to_string();
+    let labels = [("method", method.to_string()), ("path", path), ("status", status)];
+
+    metrics::counter!("http_requests_total", &labels).increment(1);
+    metrics::histogram!("http_requests_duration_seconds", &labels).record(latency);
+
+    response
+}
@@ -0,0 +1,26 @@
+/// Graceful-shutdown signal shared by every binary: resolves on SIGINT
+/// (ctrl-c) or SIGTERM so `axum::serve(...).with_graceful_shutdown(...)`
+/// can drain in-flight requests instead of being killed mid-request.
+pub async fn signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
@@ -0,0 +1,48 @@
+use axum::{middleware, routing::get, Router};
+use common::{metrics, shutdown};
+use clap::Parser;
+use std::net::{IpAddr, SocketAddr};
+use tracing::info;
+
+/// gateway — intel-svc, render-svc, and svc_api merged into one router.
+#[derive(Parser, Debug)]
+struct Cli {
+    #[arg(long, default_value = "0.0.0.0")]
+    host: IpAddr,
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let cli = Cli::parse();
+    let addr = SocketAddr::from((cli.host, cli.port));
+
+    // Each service's `routes()` already carries its own metrics
+    // instrumentation (and, for intel-svc and render-svc, its bearer
+    // auth layer; svc_api's `/` stays open); the recorder they report to is this
+    // one process-wide Prometheus recorder, installed once here.
+    let recorder_handle = metrics::install_recorder();
+
+    // `.merge` doesn't propagate a layer applied to the other side of
+    // it, so `/healthz` and `/metrics` need their own `track_metrics`
+    // rather than inheriting it from the merged service routers.
+    let health_routes = Router::new()
+        .route("/healthz", get(|| async { "ok" }))
+        .route("/metrics", get(move || std::future::ready(recorder_handle.render())))
+        .layer(middleware::from_fn(metrics::track_metrics));
+
+    let app = health_routes
+        .merge(intel_svc::routes())
+        .merge(render_svc::routes())
+        .merge(svc_api::routes());
+
+    info!("gateway listening on {}", addr);
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown::signal())
+        .await
+        .unwrap();
+}
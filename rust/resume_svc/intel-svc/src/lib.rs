@@ -0,0 +1,195 @@
+use axum::{
+    extract::Extension,
+    middleware,
+    response::sse::{Event, KeepAlive, Sse},
+    routing::get,
+    routing::post,
+    Router,
+};
+use common::auth::{self, Claims};
+use common::error::{AppError, Json};
+use common::metrics;
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+
+#[derive(Deserialize)]
+struct LookupReq { domain: String, role_family: Option<String> }
+
+#[derive(Serialize)]
+struct LookupResp {
+    domain: String,
+    role_family: String,
+    products: Vec<String>,
+    people: Vec<Person>,
+    signals: Vec<String>,
+    sources: Vec<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct Person { name: String, title: String, linkedin: String }
+
+async fn health() -> &'static str { "ok" }
+
+fn require_scope(claims: &Claims, scope: &str) -> Result<(), AppError> {
+    if claims.has_scope(scope) {
+        Ok(())
+    } else {
+        Err(AppError::forbidden(format!("missing required scope: {scope}")))
+    }
+}
+
+fn resolve_role_family(role_family: Option<String>) -> String {
+    role_family.unwrap_or_else(|| "General".to_string())
+}
+
+async fn lookup_company(
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<LookupReq>,
+) -> Result<Json<LookupResp>, AppError> {
+    require_scope(&claims, "intel:read")?;
+    let role = resolve_role_family(req.role_family);
+    Ok(Json(LookupResp {
+        domain: req.domain,
+        role_family: role,
+        products: vec!["ExampleProduct".into()],
+        people: vec![Person { name: "Jane Doe".into(), title: "Hiring Manager".into(), linkedin: "https://linkedin.com/in/janedoe".into() }],
+        signals: vec!["Recent funding".into(), "Hiring push".into()],
+        sources: vec!["https://example.com".into()],
+    }))
+}
+
+/// Same discovery as `lookup_company`, but emitted as one SSE event per
+/// sub-resource so a client can render people/signals/sources as they're
+/// found instead of waiting on the slowest upstream scraper.
+async fn lookup_company_stream(
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<LookupReq>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    require_scope(&claims, "intel:read")?;
+    let role = resolve_role_family(req.role_family);
+
+    let stream = async_stream::stream! {
+        for product in ["ExampleProduct"] {
+            yield Ok(Event::default().event("product").json_data(product).unwrap());
+        }
+
+        let people = [Person {
+            name: "Jane Doe".into(),
+            title: "Hiring Manager".into(),
+            linkedin: "https://linkedin.com/in/janedoe".into(),
+        }];
+        for person in people {
+            yield Ok(Event::default().event("person").json_data(person).unwrap());
+        }
+
+        for signal in ["Recent funding", "Hiring push"] {
+            yield Ok(Event::default().event("signal").json_data(signal).unwrap());
+        }
+
+        for source in ["https://example.com"] {
+            yield Ok(Event::default().event("source").json_data(source).unwrap());
+        }
+
+        yield Ok(Event::default()
+            .event("done")
+            .json_data(serde_json::json!({ "role_family": role }))
+            .unwrap());
+    };
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Business routes for intel-svc, auth-gated and metrics-instrumented.
+/// Mountable standalone (via [`app`]) or merged into the gateway binary.
+pub fn routes() -> Router {
+    Router::new()
+        .route("/intel/lookup_company", post(lookup_company))
+        .route("/intel/lookup_company/stream", post(lookup_company_stream))
+        .route_layer(middleware::from_fn(auth::require_bearer))
+        .layer(middleware::from_fn(metrics::track_metrics))
+}
+
+/// The full standalone app: [`routes`] plus this service's own
+/// `/healthz` and `/metrics`, instrumented the same as every other route
+/// (`.merge` doesn't propagate a layer applied to the other side of it,
+/// so these need their own `track_metrics` rather than inheriting
+/// `routes()`'s).
+pub fn app() -> Router {
+    let recorder_handle = metrics::install_recorder();
+    let health_routes = Router::new()
+        .route("/healthz", get(health))
+        .route("/metrics", get(move || std::future::ready(recorder_handle.render())))
+        .layer(middleware::from_fn(metrics::track_metrics));
+
+    health_routes.merge(routes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    const TEST_SECRET: &str = "intel-svc-test-secret";
+
+    fn token(scopes: &[&str]) -> String {
+        std::env::set_var("AUTH_JWT_SECRET", TEST_SECRET);
+        let claims =
+            serde_json::json!({ "sub": "tester", "scopes": scopes, "exp": 9_999_999_999u64 });
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(TEST_SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    fn lookup_request(role_family: Option<&str>, scopes: &[&str]) -> Request<Body> {
+        let body = serde_json::json!({ "domain": "example.com", "role_family": role_family });
+        Request::builder()
+            .method("POST")
+            .uri("/intel/lookup_company")
+            .header("authorization", format!("Bearer {}", token(scopes)))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn arbitrary_role_family_is_accepted() {
+        let request = lookup_request(Some("Customer Success"), &["intel:read"]);
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn missing_scope_is_rejected_with_structured_error() {
+        let request = lookup_request(Some("General"), &[]);
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed["error"]["code"], 403);
+    }
+
+    #[tokio::test]
+    async fn healthz_is_instrumented_in_metrics() {
+        let app = app();
+
+        let healthz = Request::builder().uri("/healthz").body(Body::empty()).unwrap();
+        app.clone().oneshot(healthz).await.unwrap();
+
+        let metrics_request = Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = app.oneshot(metrics_request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(body.contains("path=\"/healthz\""), "metrics body: {body}");
+    }
+}
@@ -0,0 +1,150 @@
+pub mod pdf;
+pub mod resume;
+pub mod storage;
+pub mod template;
+
+use axum::{
+    extract::Query,
+    http::{header, HeaderMap},
+    middleware,
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Router,
+};
+use common::auth;
+use common::error::{AppError, Json};
+use common::metrics;
+use resume::Resume;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct RenderReq {
+    cv_json: serde_json::Value,
+    #[serde(default)]
+    output: Option<OutputMode>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum OutputMode {
+    Inline,
+    Upload,
+}
+
+#[derive(Deserialize)]
+struct RenderQuery {
+    format: Option<String>,
+}
+
+/// Picks inline-bytes vs. an upload URL: an explicit `output` field wins,
+/// otherwise an `Accept: application/json` caller gets a URL back instead
+/// of a raw PDF body.
+fn resolve_output_mode(output: Option<OutputMode>, accept: Option<&str>) -> OutputMode {
+    let accept_wants_json = accept.map(|v| v.contains("application/json")).unwrap_or(false);
+    output.unwrap_or(if accept_wants_json { OutputMode::Upload } else { OutputMode::Inline })
+}
+
+async fn render(
+    Query(query): Query<RenderQuery>,
+    headers: HeaderMap,
+    Json(req): Json<RenderReq>,
+) -> Result<Response, AppError> {
+    let resume: Resume = serde_json::from_value(req.cv_json)
+        .map_err(|e| AppError::bad_request(format!("invalid cv_json: {e}")))?;
+
+    let html = template::render_html(&resume)?;
+
+    if query.format.as_deref() == Some("html") {
+        return Ok(Html(html).into_response());
+    }
+
+    let pdf_bytes = pdf::html_to_pdf(&html).await?;
+
+    let accept = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok());
+    let mode = resolve_output_mode(req.output, accept);
+
+    match mode {
+        OutputMode::Inline => {
+            Ok(([(header::CONTENT_TYPE, "application/pdf")], pdf_bytes).into_response())
+        }
+        OutputMode::Upload => {
+            let pdf_url = storage::upload_pdf(pdf_bytes).await?;
+            Ok(Json(serde_json::json!({ "pdf_url": pdf_url })).into_response())
+        }
+    }
+}
+
+/// Business routes for render-svc, auth-gated and metrics-instrumented.
+/// Mountable standalone (via [`app`]) or merged into the gateway binary.
+pub fn routes() -> Router {
+    Router::new()
+        .route("/render/resume", post(render))
+        .route_layer(middleware::from_fn(auth::require_bearer))
+        .layer(middleware::from_fn(metrics::track_metrics))
+}
+
+/// The full standalone app: [`routes`] plus this service's own `/metrics`,
+/// instrumented the same as every other route (`.merge` doesn't propagate
+/// a layer applied to the other side of it, so `/metrics` needs its own
+/// `track_metrics` rather than inheriting `routes()`'s).
+pub fn app() -> Router {
+    let recorder_handle = metrics::install_recorder();
+    let health_routes = Router::new()
+        .route("/metrics", get(move || std::future::ready(recorder_handle.render())))
+        .layer(middleware::from_fn(metrics::track_metrics));
+
+    health_routes.merge(routes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[test]
+    fn output_mode_defaults_to_inline() {
+        assert_eq!(resolve_output_mode(None, None), OutputMode::Inline);
+    }
+
+    #[test]
+    fn output_mode_follows_json_accept_header() {
+        assert_eq!(resolve_output_mode(None, Some("application/json")), OutputMode::Upload);
+    }
+
+    #[test]
+    fn explicit_output_field_overrides_accept_header() {
+        assert_eq!(
+            resolve_output_mode(Some(OutputMode::Inline), Some("application/json")),
+            OutputMode::Inline
+        );
+    }
+
+    fn bearer_token() -> String {
+        std::env::set_var("AUTH_JWT_SECRET", "test-secret");
+        let claims = serde_json::json!({ "sub": "tester", "scopes": [], "exp": 9_999_999_999u64 });
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(b"test-secret"),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn format_html_query_returns_html_without_invoking_pdf_rendering() {
+        let body = serde_json::json!({ "cv_json": { "name": "Ada Lovelace" } });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/render/resume?format=html")
+            .header("authorization", format!("Bearer {}", bearer_token()))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -1,19 +1,28 @@
-use axum::{routing::post, Router, Json, response::IntoResponse};
-use serde::Deserialize;
+use clap::Parser;
+use common::shutdown;
+use std::net::{IpAddr, SocketAddr};
 use tracing::info;
 
-#[derive(Deserialize)]
-struct RenderReq { cv_json: serde_json::Value }
-
-async fn render(Json(_req): Json<RenderReq>) -> impl IntoResponse {
-    Json(serde_json::json!({ "pdf_url": "s3://bucket/fake.pdf" }))
+/// render-svc — renders resumes to PDF.
+#[derive(Parser, Debug)]
+struct Cli {
+    #[arg(long, default_value = "0.0.0.0")]
+    host: IpAddr,
+    #[arg(long, default_value_t = 8082)]
+    port: u16,
 }
 
 #[tokio::main]
 async fn main() {
     tracing_subscriber::fmt().with_target(false).init();
-    let app = Router::new().route("/render/resume", post(render));
-    let addr = std::net::SocketAddr::from(([0,0,0,0], 8082));
+
+    let cli = Cli::parse();
+    let addr = SocketAddr::from((cli.host, cli.port));
     info!("render-svc listening on {}", addr);
-    axum::serve(tokio::net::TcpListener::bind(addr).await.unwrap(), app).await.unwrap();
+
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    axum::serve(listener, render_svc::app())
+        .with_graceful_shutdown(shutdown::signal())
+        .await
+        .unwrap();
 }
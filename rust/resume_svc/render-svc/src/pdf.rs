@@ -0,0 +1,77 @@
+use common::error::AppError;
+use once_cell::sync::Lazy;
+use std::io::Read;
+use std::sync::mpsc as std_mpsc;
+use tokio::sync::oneshot;
+use wkhtmltopdf::PdfApplication;
+
+struct PdfJob {
+    html: String,
+    reply: oneshot::Sender<Result<Vec<u8>, AppError>>,
+}
+
+/// `wkhtmltopdf` may only call `pdf_init()` once per process, and every
+/// conversion must happen from that same thread (see the crate's own
+/// docs on `PdfApplication`). So instead of constructing a fresh
+/// `PdfApplication` per request (which would fail with `IllegalInit`
+/// the second time), a single dedicated OS thread owns the one
+/// `PdfApplication` for the life of the process and renders jobs handed
+/// to it over a channel — keeping conversion off the tokio runtime too.
+static PDF_WORKER: Lazy<std_mpsc::Sender<PdfJob>> = Lazy::new(|| {
+    let (tx, rx) = std_mpsc::channel();
+    std::thread::spawn(move || pdf_worker_loop(rx));
+    tx
+});
+
+fn pdf_worker_loop(jobs: std_mpsc::Receiver<PdfJob>) {
+    let mut app = match PdfApplication::new() {
+        Ok(app) => app,
+        Err(e) => {
+            let message = format!("failed to start wkhtmltopdf: {e}");
+            for job in jobs {
+                let _ = job.reply.send(Err(AppError::internal(message.clone())));
+            }
+            return;
+        }
+    };
+
+    for job in jobs {
+        // `render_once` can panic (e.g. wkhtmltopdf rejects embedded NUL
+        // bytes via an `expect`). Catching it here keeps this one
+        // long-lived thread alive for later requests instead of taking
+        // every future render down with it.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            render_once(&mut app, &job.html)
+        }))
+        .unwrap_or_else(|_| Err(AppError::internal("pdf render task panicked".to_string())));
+
+        let _ = job.reply.send(result);
+    }
+}
+
+fn render_once(app: &mut PdfApplication, html: &str) -> Result<Vec<u8>, AppError> {
+    let mut output = app
+        .builder()
+        .title("Resume")
+        .build_from_html(html)
+        .map_err(|e| AppError::internal(format!("failed to render pdf: {e}")))?;
+
+    let mut bytes = Vec::new();
+    output
+        .read_to_end(&mut bytes)
+        .map_err(|e| AppError::internal(format!("failed to read rendered pdf: {e}")))?;
+    Ok(bytes)
+}
+
+/// Converts rendered resume HTML into PDF bytes via the dedicated
+/// `wkhtmltopdf` worker thread, without blocking the calling tokio task.
+pub async fn html_to_pdf(html: &str) -> Result<Vec<u8>, AppError> {
+    let (reply_tx, reply_rx) = oneshot::channel();
+    PDF_WORKER
+        .send(PdfJob { html: html.to_owned(), reply: reply_tx })
+        .map_err(|_| AppError::internal("pdf worker thread is not running".to_string()))?;
+
+    reply_rx
+        .await
+        .map_err(|_| AppError::internal("pdf worker thread dropped the reply channel".to_string()))?
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// Typed shape of the `cv_json` blob a `RenderReq` carries, deserialized
+/// so the HTML template can address fields directly instead of poking
+/// around in a raw `serde_json::Value`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Resume {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    #[serde(default)]
+    pub experience: Vec<ExperienceEntry>,
+    #[serde(default)]
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Section {
+    pub heading: String,
+    #[serde(default)]
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ExperienceEntry {
+    pub company: String,
+    pub role: String,
+    #[serde(default)]
+    pub start: String,
+    #[serde(default)]
+    pub end: String,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+}
@@ -0,0 +1,25 @@
+use common::error::AppError;
+use object_store::{aws::AmazonS3Builder, path::Path, ObjectStore};
+use std::sync::Arc;
+
+/// Uploads rendered PDF bytes to the configured S3-compatible bucket
+/// (`RESUME_PDF_BUCKET`, credentials from the environment) and returns
+/// the object's `s3://` URL.
+pub async fn upload_pdf(bytes: Vec<u8>) -> Result<String, AppError> {
+    let bucket = std::env::var("RESUME_PDF_BUCKET").unwrap_or_else(|_| "resumes".to_string());
+
+    let store: Arc<dyn ObjectStore> = Arc::new(
+        AmazonS3Builder::from_env()
+            .with_bucket_name(&bucket)
+            .build()
+            .map_err(|e| AppError::bad_gateway(format!("failed to configure object store: {e}")))?,
+    );
+
+    let key = format!("{}.pdf", uuid::Uuid::new_v4());
+    store
+        .put(&Path::from(key.clone()), bytes.into())
+        .await
+        .map_err(|e| AppError::bad_gateway(format!("failed to upload pdf: {e}")))?;
+
+    Ok(format!("s3://{bucket}/{key}"))
+}
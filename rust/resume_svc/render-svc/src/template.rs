@@ -0,0 +1,21 @@
+use crate::resume::Resume;
+use common::error::AppError;
+use handlebars::Handlebars;
+use once_cell::sync::Lazy;
+
+const TEMPLATE_SOURCE: &str = include_str!("../templates/resume.hbs");
+
+static REGISTRY: Lazy<Handlebars<'static>> = Lazy::new(|| {
+    let mut hb = Handlebars::new();
+    hb.register_template_string("resume", TEMPLATE_SOURCE)
+        .expect("templates/resume.hbs is valid handlebars");
+    hb
+});
+
+/// Renders a `Resume` into the intermediate HTML that is later converted
+/// to PDF (or returned as-is when debugging via `?format=html`).
+pub fn render_html(resume: &Resume) -> Result<String, AppError> {
+    REGISTRY
+        .render("resume", resume)
+        .map_err(|e| AppError::internal(format!("failed to render resume template: {e}")))
+}
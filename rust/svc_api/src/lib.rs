@@ -0,0 +1,23 @@
+use axum::{middleware, routing::get, Router};
+use common::metrics;
+
+/// Business routes for svc_api, metrics-instrumented. Mountable
+/// standalone (via [`app`]) or merged into the gateway binary.
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(|| async { "ok" }))
+        .layer(middleware::from_fn(metrics::track_metrics))
+}
+
+/// The full standalone app: [`routes`] plus this service's own `/metrics`,
+/// instrumented the same as every other route (`.merge` doesn't propagate
+/// a layer applied to the other side of it, so `/metrics` needs its own
+/// `track_metrics` rather than inheriting `routes()`'s).
+pub fn app() -> Router {
+    let recorder_handle = metrics::install_recorder();
+    let health_routes = Router::new()
+        .route("/metrics", get(move || std::future::ready(recorder_handle.render())))
+        .layer(middleware::from_fn(metrics::track_metrics));
+
+    health_routes.merge(routes())
+}
@@ -1,12 +1,25 @@
-use axum::{routing::get, Router};
-use std::net::SocketAddr;
+use clap::Parser;
+use common::shutdown;
+use std::net::{IpAddr, SocketAddr};
+
+/// svc_api — placeholder gateway-adjacent health service.
+#[derive(Parser, Debug)]
+struct Cli {
+    #[arg(long, default_value = "127.0.0.1")]
+    host: IpAddr,
+    #[arg(long, default_value_t = 3001)]
+    port: u16,
+}
 
 #[tokio::main]
 async fn main() {
-    let app = Router::new().route("/", get(|| async { "ok" }));
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3001));
+    let cli = Cli::parse();
+    let addr = SocketAddr::from((cli.host, cli.port));
     println!("svc_api listening on http://{addr}");
-    axum::serve(tokio::net::TcpListener::bind(addr).await.unwrap(), app)
+
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    axum::serve(listener, svc_api::app())
+        .with_graceful_shutdown(shutdown::signal())
         .await
         .unwrap();
 }